@@ -5,8 +5,31 @@ use std::{
 	fmt,
 	str,
 	num::ParseFloatError,
+	ops::{Add, Div, Mul, Neg, Sub},
 };
 
+use num_traits::{Float, FloatConst, NumCast};
+
+/// Convert an `f64` literal into whatever float type `T` backs an `Angle<T>`.
+///
+/// Panics if `x` cannot be represented in `T`, which should never happen for the small, fixed
+/// literals (conversion factors, DMS components, etc.) this module uses it for.
+fn lit<T: NumCast>(x: f64) -> T {
+	T::from(x).expect("literal should be representable in T")
+}
+
+/// Convert `x` to an `i32`, saturating to `i32::MIN`/`i32::MAX` instead of panicking or wrapping
+/// if `x` is out of range, matching the behavior of the `as i32` casts this replaces.
+fn saturating_to_i32<T: Float + NumCast>(x: T) -> i32 {
+	x.to_i32().unwrap_or(if x.is_nan() { 0 } else if x.is_sign_negative() { i32::MIN } else { i32::MAX })
+}
+
+/// Convert `x` to a `u32`, saturating to `0`/`u32::MAX` instead of panicking or wrapping if `x`
+/// is out of range, matching the behavior of the `as u32` casts this replaces.
+fn saturating_to_u32<T: Float + NumCast>(x: T) -> u32 {
+	x.to_u32().unwrap_or(if x.is_nan() || x.is_sign_negative() { 0 } else { u32::MAX })
+}
+
 /// A wrapper type used for conveniently converting between degrees and radians.
 ///
 /// Many APIs involving geometry and trigonometry require users to read the documentation on whether angles
@@ -16,25 +39,30 @@ use std::{
 /// Although not difficult to memorize, this type takes the guesswork out of passing quantities representing angles
 /// to functions, as the function can convert to whichever angle measure (degrees or radians) that it requires regardless
 /// of whichever angle measure is passed in.
-/*
-    Currently use f64 as backing type but could change to num_traits::Float or num_traits::real::Real in the future.
-
-    Also currently only has two variants: Degrees and Radians but could add Revolutions in the future
-*/
+///
+/// `Angle` is generic over the backing floating-point type `T`, which defaults to `f64` so that existing
+/// callers (e.g. `"90°".parse::<Angle>()`) keep working unchanged. Instantiate `Angle<f32>` where a smaller
+/// footprint matters, such as turtle graphics.
 #[derive(Clone, Copy, Debug)]
-pub enum Angle {
+pub enum Angle<T: Float + FloatConst = f64> {
 	/// An angle in degrees, where one degree is defined as 1/360 of a circle.
-	Degrees(f64),
+	Degrees(T),
 	/// An angle in radians, where one radian is defined as 2pi of a revolution.
-	Radians(f64),
+	Radians(T),
+	/// An angle in gradians, where one gradian is defined as 1/400 of a circle.
+	Gradians(T),
+	/// An angle in turns, where one turn is defined as a full revolution of a circle.
+	Turns(T),
 }
 
-impl Angle {
+impl<T: Float + FloatConst> Angle<T> {
 	/// Get the underlying number that this `Angle` wraps.
-	pub fn unwrap(self) -> f64 {
+	pub fn unwrap(self) -> T {
 		match self {
 			Angle::Degrees(deg) => deg,
 			Angle::Radians(rad) => rad,
+			Angle::Gradians(grad) => grad,
+			Angle::Turns(turns) => turns,
 		}
 	}
 
@@ -56,6 +84,24 @@ impl Angle {
 		}
 	}
 
+	/// Determines if the given `Angle` is in `Gradians`.
+	pub fn is_gradians(&self) -> bool {
+		if let Angle::Gradians(_) = *self {
+			true
+		} else {
+			false
+		}
+	}
+
+	/// Determines if the given `Angle` is in `Turns`.
+	pub fn is_turns(&self) -> bool {
+		if let Angle::Turns(_) = *self {
+			true
+		} else {
+			false
+		}
+	}
+
 	/// Consume the given `Angle` and return a new one, with the new `Angle` in `Degrees`.
 	///
 	/// If the given `Angle` is already in degrees, then this function just returns the given angle.
@@ -64,6 +110,8 @@ impl Angle {
 		match self {
 			Angle::Degrees(_) => self,
 			Angle::Radians(rad) => Angle::Degrees(rad.to_degrees()),
+			Angle::Gradians(grad) => Angle::Degrees(grad * lit(0.9)),
+			Angle::Turns(turns) => Angle::Degrees(turns * lit(360.0)),
 		}
 	}
 
@@ -75,6 +123,29 @@ impl Angle {
 		match self {
 			Angle::Degrees(deg) => Angle::Radians(deg.to_radians()),
 			Angle::Radians(_) => self,
+			Angle::Gradians(_) | Angle::Turns(_) => Angle::Radians(self.to_degrees().unwrap().to_radians()),
+		}
+	}
+
+	/// Consume the given `Angle` and return a new one, with the new `Angle` in `Gradians`.
+	///
+	/// If the given `Angle` is already in gradians, then this function just returns the given angle.
+	/// Otherwise, this function performs the conversion, pivoting through degrees (1 gradian = 0.9°).
+	pub fn to_gradians(self) -> Self {
+		match self {
+			Angle::Gradians(_) => self,
+			_ => Angle::Gradians(self.to_degrees().unwrap() / lit(0.9)),
+		}
+	}
+
+	/// Consume the given `Angle` and return a new one, with the new `Angle` in `Turns`.
+	///
+	/// If the given `Angle` is already in turns, then this function just returns the given angle.
+	/// Otherwise, this function performs the conversion, pivoting through degrees (1 turn = 360°).
+	pub fn to_turns(self) -> Self {
+		match self {
+			Angle::Turns(_) => self,
+			_ => Angle::Turns(self.to_degrees().unwrap() / lit(360.0)),
 		}
 	}
 
@@ -83,43 +154,273 @@ impl Angle {
 	/// Returns a tuple of three integers: the first represents the number of degrees in the given `Angle`, the second represents
 	/// the number of minutes in the given `Angle`, and the third represents the number of seconds in the given `Angle`. While a
 	/// whole angle can be negative, the number of minutes and seconds in an angle cannot, so the first integer in the tuple is
-	/// signed while the the other two are not.
+	/// signed while the the other two are not. The sign of the whole angle is carried on the degrees field; by convention, an
+	/// angle with a magnitude under one degree (so its degrees field is `0`) cannot express its sign this way, and is treated
+	/// as non-negative, so `0° 30′` and `-0° 30′` both come out as `(0, 30, 0)`. `to_dms_parts` follows the same convention.
 	///
 	/// A minute is 1/60 of a degree and a second is 1/60 of a minute (1/3600 of a degree).
 	pub fn to_dms(self) -> (i32, u32, u32) {
+		let (mut d, mut m, s) = self.to_dms_parts();
+
+		// Round rather than truncate: the chained division/multiplication in to_dms_parts can leave
+		// a whole number of seconds a hair under its true value (e.g. 14.999999999999996), which
+		// truncation would report as one second short. Rounding can in turn produce a seconds value
+		// of 60, so carry that into minutes (and a minutes value of 60 into degrees) to keep both
+		// fields within their documented [0, 60) range.
+		let mut s = saturating_to_u32(s.round());
+
+		if s == 60 {
+			s = 0;
+			m += 1;
+		}
+		if m == 60 {
+			m = 0;
+			d += if d < 0 { -1 } else { 1 };
+		}
+
+		(d, m, s)
+	}
+
+	/// Like `to_dms`, but the seconds field retains its fractional part instead of being rounded
+	/// to a whole number, so sub-arcsecond precision round-trips.
+	pub fn to_dms_parts(self) -> (i32, u32, T) {
 		let dd = self.to_degrees().unwrap();
+		let negative = dd < T::zero();
+		let abs_dd = dd.abs();
 
-		let d = dd.trunc();
-		let m = ((dd - d) * 60.0).trunc();
-		let s = (dd - d - m/60.0) * 3600.0;
+		let d = abs_dd.trunc();
+		let m = ((abs_dd - d) * lit(60.0)).trunc();
+		let s = (abs_dd - d - m / lit(60.0)) * lit(3600.0);
+
+		let d = if negative { -d } else { d };
+
+		(saturating_to_i32(d), saturating_to_u32(m), s)
+	}
 
-		(d as i32, m as u32, s as u32)
+	/// Convert the given `Angle` to milliarcseconds, where 1 milliarcsecond (mas) is 1/3,600,000 of a degree.
+	pub fn to_milliarcseconds(self) -> T {
+		self.to_degrees().unwrap() * lit(3_600_000.0)
 	}
 
 	/// Create a new `Angle` from a tuple of degrees, minutes, and seconds.
 	///
 	/// This method is the inverse of `to_dms`, i.e. passing the `Angle` returned by this function to `to_dms` will return the same tuple
-	/// used to invoke this function.
+	/// used to invoke this function. As with `to_dms`, the sign of the whole angle is carried on the degrees field, so the minutes
+	/// and seconds fields are always treated as magnitudes regardless of sign.
 	pub fn from_dms(theta: (i32, u32, u32)) -> Self {
-		let d = theta.0 as f64;
-		let m = theta.1 as f64;
-		let s = theta.2 as f64;
+		let negative = theta.0 < 0;
 
-		let dd = d + m/60.0 + s/3600.0;
+		let d: T = lit(theta.0.abs() as f64);
+		let m: T = lit(theta.1 as f64);
+		let s: T = lit(theta.2 as f64);
 
-		Angle::Degrees(dd)
+		let magnitude = d + m / lit(60.0) + s / lit(3600.0);
+
+		Angle::Degrees(if negative { -magnitude } else { magnitude })
+	}
+
+	/// Build a new `Angle` in the same unit as `self`, wrapping `value`.
+	fn matching(self, value: T) -> Self {
+		match self {
+			Angle::Degrees(_) => Angle::Degrees(value),
+			Angle::Radians(_) => Angle::Radians(value),
+			Angle::Gradians(_) => Angle::Gradians(value),
+			Angle::Turns(_) => Angle::Turns(value),
+		}
+	}
+
+	/// Convert `self` into whichever unit `unit` is in, returning the bare number.
+	fn value_in_unit_of(self, unit: Angle<T>) -> T {
+		match unit {
+			Angle::Degrees(_) => self.to_degrees().unwrap(),
+			Angle::Radians(_) => self.to_radians().unwrap(),
+			Angle::Gradians(_) => self.to_gradians().unwrap(),
+			Angle::Turns(_) => self.to_turns().unwrap(),
+		}
+	}
+
+	/// The magnitude of a full turn (360°, 2π rad., 400ᵍ, or 1 turn) expressed in `self`'s unit.
+	fn full_turn_magnitude(&self) -> T {
+		match self {
+			Angle::Degrees(_) => lit(360.0),
+			Angle::Radians(_) => lit::<T>(2.0) * T::PI(),
+			Angle::Gradians(_) => lit(400.0),
+			Angle::Turns(_) => T::one(),
+		}
+	}
+
+	/// Normalize `self` into `[0, full_turn)`, expressed in `self`'s current unit.
+	///
+	/// For example, `Angle::Degrees(400.0).wrap()` yields `Angle::Degrees(40.0)`.
+	pub fn wrap(self) -> Self {
+		let full_turn = self.full_turn_magnitude();
+		let value = self.unwrap();
+		let remainder = value % full_turn;
+
+		self.matching(if remainder < T::zero() {
+			remainder + full_turn
+		} else {
+			remainder
+		})
+	}
+
+	/// Normalize `self` into `(-half_turn, half_turn]`, expressed in `self`'s current unit.
+	///
+	/// For example, `Angle::Degrees(270.0).normalize_signed()` yields `Angle::Degrees(-90.0)`.
+	pub fn normalize_signed(self) -> Self {
+		let full_turn = self.full_turn_magnitude();
+		let half_turn = full_turn / lit(2.0);
+		let wrapped = self.wrap().unwrap();
+
+		self.matching(if wrapped > half_turn {
+			wrapped - full_turn
+		} else {
+			wrapped
+		})
+	}
+
+	/// The angle obtained by rotating `self` by a half turn, wrapped back into `[0, full_turn)`.
+	pub fn opposite(self) -> Self {
+		let half_turn = self.matching(self.full_turn_magnitude() / lit(2.0));
+		(self + half_turn).wrap()
+	}
+
+	/// The angle of a full turn, i.e. 360°.
+	pub fn full_turn() -> Self {
+		Angle::Degrees(lit(360.0))
+	}
+
+	/// The angle of a half turn, i.e. 180°.
+	pub fn half_turn() -> Self {
+		Angle::Degrees(lit(180.0))
+	}
+
+	/// The angle of a quarter turn, i.e. 90°.
+	pub fn quarter_turn() -> Self {
+		Angle::Degrees(lit(90.0))
+	}
+
+	/// Returns `self` unchanged if its magnitude in degrees is at most `limit_deg`, otherwise builds
+	/// `err` from its signed value in degrees. Shared by `try_acute`, `try_obtuse_or_less`, and `try_within_turn`.
+	fn try_within_degrees(self, limit_deg: f64, err: impl FnOnce(f64) -> AngleNotInRange) -> Result<Self, AngleNotInRange> {
+		let deg = self.to_degrees().unwrap();
+		if deg.abs() > lit(limit_deg) {
+			Err(err(deg.to_f64().expect("degrees should fit in f64")))
+		} else {
+			Ok(self)
+		}
+	}
+
+	/// Construct `self`, but only if its magnitude is at most 90° (i.e. it is acute or right).
+	///
+	/// Returns `Err(AngleNotInRange::ObtuseAngle)` otherwise.
+	pub fn try_acute(self) -> Result<Self, AngleNotInRange> {
+		self.try_within_degrees(90.0, AngleNotInRange::ObtuseAngle)
+	}
+
+	/// Construct `self`, but only if its magnitude is at most 180° (i.e. it is not reflex).
+	///
+	/// Returns `Err(AngleNotInRange::ReflexAngle)` otherwise.
+	pub fn try_obtuse_or_less(self) -> Result<Self, AngleNotInRange> {
+		self.try_within_degrees(180.0, AngleNotInRange::ReflexAngle)
+	}
+
+	/// Construct `self`, but only if its magnitude is at most 360° (i.e. no more than a full turn).
+	///
+	/// Returns `Err(AngleNotInRange::FullTurnExceeded)` otherwise.
+	pub fn try_within_turn(self) -> Result<Self, AngleNotInRange> {
+		self.try_within_degrees(360.0, AngleNotInRange::FullTurnExceeded)
+	}
+
+	/// Like `from_dms`, but rejects minutes or seconds fields that are out of their `[0, 60)` range.
+	pub fn from_dms_checked(theta: (i32, u32, u32)) -> Result<Self, AngleNotInRange> {
+		if theta.1 >= 60 {
+			return Err(AngleNotInRange::ArcMinutesOutOfRange(theta.1));
+		}
+		if theta.2 >= 60 {
+			return Err(AngleNotInRange::ArcSecondsOutOfRange(theta.2));
+		}
+
+		Ok(Self::from_dms(theta))
 	}
 }
 
-impl fmt::Display for Angle {
+impl<T: Float + FloatConst> Add for Angle<T> {
+	type Output = Angle<T>;
+
+	/// Add two `Angle`s together. The unit of the left-hand side is preserved; the right-hand side
+	/// is converted into that unit before the values are summed.
+	fn add(self, rhs: Angle<T>) -> Angle<T> {
+		self.matching(self.unwrap() + rhs.value_in_unit_of(self))
+	}
+}
+
+impl<T: Float + FloatConst> Sub for Angle<T> {
+	type Output = Angle<T>;
+
+	/// Subtract one `Angle` from another. The unit of the left-hand side is preserved; the right-hand
+	/// side is converted into that unit before the values are subtracted.
+	fn sub(self, rhs: Angle<T>) -> Angle<T> {
+		self.matching(self.unwrap() - rhs.value_in_unit_of(self))
+	}
+}
+
+impl<T: Float + FloatConst> Neg for Angle<T> {
+	type Output = Angle<T>;
+
+	/// Negate an `Angle`, preserving its unit.
+	fn neg(self) -> Angle<T> {
+		self.matching(-self.unwrap())
+	}
+}
+
+impl<T: Float + FloatConst> Mul<T> for Angle<T> {
+	type Output = Angle<T>;
+
+	/// Scale an `Angle` by a scalar, preserving its unit.
+	fn mul(self, rhs: T) -> Angle<T> {
+		self.matching(self.unwrap() * rhs)
+	}
+}
+
+impl<T: Float + FloatConst> Div<T> for Angle<T> {
+	type Output = Angle<T>;
+
+	/// Divide an `Angle` by a scalar, preserving its unit.
+	fn div(self, rhs: T) -> Angle<T> {
+		self.matching(self.unwrap() / rhs)
+	}
+}
+
+impl<T: Float + FloatConst + fmt::Display> fmt::Display for Angle<T> {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		match *self {
 			Angle::Degrees(deg) => write!(f, "{}°", deg),
 			Angle::Radians(rad) => write!(f, "{} rad.", rad),
+			Angle::Gradians(grad) => write!(f, "{}ᵍ", grad),
+			Angle::Turns(turns) => write!(f, "{} turn", turns),
 		}
 	}
 }
 
+/// Displays an `Angle` in sexagesimal `D° M′ S″` form, rather than the raw decimal that `Angle`'s own
+/// `Display` impl prints. Obtained via `Angle::dms`.
+pub struct Dms<T: Float + FloatConst>(Angle<T>);
+
+impl<T: Float + FloatConst> Angle<T> {
+	/// Wrap this `Angle` so that formatting it with `{}` prints `D° M′ S″` instead of a raw decimal.
+	pub fn dms(self) -> Dms<T> {
+		Dms(self)
+	}
+}
+
+impl<T: Float + FloatConst> fmt::Display for Dms<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let (d, m, s) = self.0.to_dms();
+		write!(f, "{}° {}′ {}″", d, m, s)
+	}
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum ParseAngleError {
 	UnrecognizedUnit,
@@ -144,26 +445,270 @@ impl error::Error for ParseAngleError {
 	}
 }
 
-impl str::FromStr for Angle {
+/// The ways a fallible `Angle` constructor (`Angle::try_acute`, `Angle::try_obtuse_or_less`,
+/// `Angle::try_within_turn`, `Angle::from_dms_checked`) can reject an out-of-range angle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AngleNotInRange {
+	/// The angle's magnitude exceeds 180°, so it is a reflex angle.
+	ReflexAngle(f64),
+	/// The angle's magnitude exceeds 90°, so it is an obtuse (or reflex) angle.
+	ObtuseAngle(f64),
+	/// The angle's magnitude exceeds 360°, i.e. more than a full turn.
+	FullTurnExceeded(f64),
+	/// The minutes component of a degrees-minutes-seconds triple was not in `[0, 60)`.
+	ArcMinutesOutOfRange(u32),
+	/// The seconds component of a degrees-minutes-seconds triple was not in `[0, 60)`.
+	ArcSecondsOutOfRange(u32),
+}
+
+impl fmt::Display for AngleNotInRange {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			AngleNotInRange::ReflexAngle(deg) =>
+				write!(f, "{}° is a reflex angle (> 180°), but an angle of at most a half turn was expected", deg),
+			AngleNotInRange::ObtuseAngle(deg) =>
+				write!(f, "{}° is an obtuse angle (> 90°), but an angle of at most a right angle was expected", deg),
+			AngleNotInRange::FullTurnExceeded(deg) =>
+				write!(f, "{}° exceeds a full turn (> 360°)", deg),
+			AngleNotInRange::ArcMinutesOutOfRange(m) =>
+				write!(f, "{}′ is out of range; arcminutes must be in [0, 60)", m),
+			AngleNotInRange::ArcSecondsOutOfRange(s) =>
+				write!(f, "{}″ is out of range; arcseconds must be in [0, 60)", s),
+		}
+	}
+}
+
+impl error::Error for AngleNotInRange {}
+
+impl<T: Float + FloatConst> str::FromStr for Angle<T> {
 	type Err = ParseAngleError;
 
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		if s.ends_with('º') {
-			let deg_str = s.trim_end_matches('º').trim_end();
+		// Parsed as f64 and then cast into T, since ParseAngleError wraps the concrete
+		// std::num::ParseFloatError that only f64::from_str produces.
+		if s.contains('′') {
+			let trimmed = s.trim();
+			let negative = trimmed.starts_with('-');
+			let trimmed = trimmed.trim_start_matches('-').trim_start();
+
+			let (deg_str, rest) = trimmed.split_once('°').ok_or(ParseAngleError::UnrecognizedUnit)?;
+			let deg = deg_str.trim().parse::<f64>().map_err(ParseAngleError::ParseFloatError)?;
+
+			let (min_str, rest) = rest.trim().split_once('′').ok_or(ParseAngleError::UnrecognizedUnit)?;
+			let min = min_str.trim().parse::<f64>().map_err(ParseAngleError::ParseFloatError)?;
+
+			let sec_str = rest.trim().trim_end_matches('″').trim();
+			let sec = if sec_str.is_empty() {
+				0.0
+			} else {
+				sec_str.parse::<f64>().map_err(ParseAngleError::ParseFloatError)?
+			};
+
+			let magnitude = deg + min / 60.0 + sec / 3600.0;
+			let dd = if negative { -magnitude } else { magnitude };
+
+			Ok(Angle::Degrees(lit(dd)))
+		} else if s.ends_with('°') || s.ends_with('º') {
+			// '°' (U+00B0 DEGREE SIGN) is what Display emits; 'º' (U+00BA MASCULINE ORDINAL INDICATOR)
+			// is accepted too for backward compatibility with older input.
+			let deg_str = s.trim_end_matches(['°', 'º']).trim_end();
 			let deg = match deg_str.parse::<f64>() {
 				Ok(d) => d,
 				Err(e) => return Err(ParseAngleError::ParseFloatError(e)),
 			};
-			Ok(Angle::Degrees(deg))
+			Ok(Angle::Degrees(lit(deg)))
 		} else if s.ends_with("rad.") {
 			let rad_str = s.trim_end_matches("rad.").trim_end();
 			let rad = match rad_str.parse::<f64>() {
 				Ok(r) => r,
 				Err(e) => return Err(ParseAngleError::ParseFloatError(e)),
 			};
-			Ok(Angle::Radians(rad))
+			Ok(Angle::Radians(lit(rad)))
+		} else if s.ends_with('ᵍ') {
+			let grad_str = s.trim_end_matches('ᵍ').trim_end();
+			let grad = match grad_str.parse::<f64>() {
+				Ok(g) => g,
+				Err(e) => return Err(ParseAngleError::ParseFloatError(e)),
+			};
+			Ok(Angle::Gradians(lit(grad)))
+		} else if s.ends_with("grad") {
+			let grad_str = s.trim_end_matches("grad").trim_end();
+			let grad = match grad_str.parse::<f64>() {
+				Ok(g) => g,
+				Err(e) => return Err(ParseAngleError::ParseFloatError(e)),
+			};
+			Ok(Angle::Gradians(lit(grad)))
+		} else if s.ends_with("gon") {
+			let grad_str = s.trim_end_matches("gon").trim_end();
+			let grad = match grad_str.parse::<f64>() {
+				Ok(g) => g,
+				Err(e) => return Err(ParseAngleError::ParseFloatError(e)),
+			};
+			Ok(Angle::Gradians(lit(grad)))
+		} else if s.ends_with("turn") {
+			let turn_str = s.trim_end_matches("turn").trim_end();
+			let turns = match turn_str.parse::<f64>() {
+				Ok(t) => t,
+				Err(e) => return Err(ParseAngleError::ParseFloatError(e)),
+			};
+			Ok(Angle::Turns(lit(turns)))
 		} else {
 			Err(ParseAngleError::UnrecognizedUnit)
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn degrees_round_trip_through_display_and_from_str() {
+		let angle = Angle::Degrees(90.0);
+		let parsed: Angle = angle.to_string().parse().unwrap();
+
+		assert!(parsed.is_degrees());
+		assert_eq!(parsed.unwrap(), 90.0);
+	}
+
+	#[test]
+	fn a_full_turn_converts_to_every_unit() {
+		let turn = Angle::Turns(1.0);
+
+		assert_eq!(turn.to_degrees().unwrap(), 360.0);
+		assert_eq!(turn.to_gradians().unwrap(), 400.0);
+		assert!((turn.to_radians().unwrap() - 2.0 * std::f64::consts::PI).abs() < 1e-10);
+	}
+
+	#[test]
+	fn gradians_and_turns_parse_from_str() {
+		let grad: Angle = "100ᵍ".parse().unwrap();
+		assert!(grad.is_gradians());
+		assert_eq!(grad.to_degrees().unwrap(), 90.0);
+
+		let turn: Angle = "0.5turn".parse().unwrap();
+		assert!(turn.is_turns());
+		assert_eq!(turn.to_degrees().unwrap(), 180.0);
+	}
+
+	#[test]
+	fn add_preserves_lhs_unit_and_converts_a_same_unit_rhs() {
+		let sum = Angle::Degrees(30.0) + Angle::Degrees(60.0);
+		assert!(sum.is_degrees());
+		assert_eq!(sum.unwrap(), 90.0);
+	}
+
+	#[test]
+	fn add_converts_a_cross_unit_rhs_into_the_lhs_unit() {
+		let sum = Angle::Degrees(90.0) + Angle::Radians(std::f64::consts::FRAC_PI_2);
+		assert!(sum.is_degrees());
+		assert!((sum.unwrap() - 180.0).abs() < 1e-10);
+	}
+
+	#[test]
+	fn sub_converts_a_cross_unit_rhs_into_the_lhs_unit() {
+		let diff = Angle::Turns(1.0) - Angle::Degrees(90.0);
+		assert!(diff.is_turns());
+		assert!((diff.unwrap() - 0.75).abs() < 1e-10);
+	}
+
+	#[test]
+	fn neg_preserves_unit() {
+		let negated = -Angle::Degrees(45.0);
+		assert!(negated.is_degrees());
+		assert_eq!(negated.unwrap(), -45.0);
+	}
+
+	#[test]
+	fn mul_and_div_scale_preserving_unit() {
+		let scaled = Angle::Degrees(45.0) * 2.0;
+		assert!(scaled.is_degrees());
+		assert_eq!(scaled.unwrap(), 90.0);
+
+		let halved = Angle::Degrees(90.0) / 2.0;
+		assert!(halved.is_degrees());
+		assert_eq!(halved.unwrap(), 45.0);
+	}
+
+	#[test]
+	fn wrap_normalizes_into_a_full_turn() {
+		assert_eq!(Angle::Degrees(400.0).wrap().unwrap(), 40.0);
+		assert_eq!(Angle::Degrees(-40.0).wrap().unwrap(), 320.0);
+	}
+
+	#[test]
+	fn normalize_signed_keeps_angles_in_a_half_turn_either_side_of_zero() {
+		assert_eq!(Angle::Degrees(270.0).normalize_signed().unwrap(), -90.0);
+		assert_eq!(Angle::Degrees(180.0).normalize_signed().unwrap(), 180.0);
+	}
+
+	#[test]
+	fn opposite_adds_a_half_turn() {
+		assert_eq!(Angle::Degrees(30.0).opposite().unwrap(), 210.0);
+	}
+
+	#[test]
+	fn to_dms_and_from_dms_round_trip_negative_angles() {
+		let angle = Angle::Degrees(-5.5);
+		assert_eq!(angle.to_dms(), (-5, 30, 0));
+		assert_eq!(Angle::<f64>::from_dms((-5, 30, 0)).to_degrees().unwrap(), -5.5);
+	}
+
+	#[test]
+	fn to_dms_parts_keeps_fractional_seconds() {
+		let (d, m, s) = Angle::Degrees(12.504166666666666).to_dms_parts();
+		assert_eq!(d, 12);
+		assert_eq!(m, 30);
+		assert!((s - 15.0).abs() < 1e-6);
+	}
+
+	#[test]
+	fn dms_string_round_trips_through_from_str_and_dms_display() {
+		let angle: Angle = "12° 30′ 15″".parse().unwrap();
+		assert_eq!(angle.to_dms(), (12, 30, 15));
+		assert_eq!(angle.dms().to_string(), "12° 30′ 15″");
+
+		let negative: Angle = "-12° 30′ 15″".parse().unwrap();
+		assert_eq!(negative.to_degrees().unwrap(), -12.504166666666666);
+	}
+
+	#[test]
+	fn to_dms_carries_a_rounded_60_seconds_into_minutes_and_degrees() {
+		let angle = Angle::Degrees(10.0 + 20.0 / 60.0 + 59.9999999999 / 3600.0);
+		assert_eq!(angle.to_dms(), (10, 21, 0));
+
+		let angle = Angle::Degrees(10.0 + 59.0 / 60.0 + 59.9999999999 / 3600.0);
+		assert_eq!(angle.to_dms(), (11, 0, 0));
+	}
+
+	#[test]
+	fn to_dms_saturates_instead_of_panicking_on_an_out_of_range_degree_magnitude() {
+		assert_eq!(Angle::Degrees(1e20).to_dms(), (i32::MAX, 0, 0));
+		assert_eq!(Angle::Degrees(-1e20).to_dms(), (i32::MIN, 0, 0));
+	}
+
+	#[test]
+	fn try_acute_rejects_angles_over_ninety_degrees() {
+		assert!(Angle::Degrees(45.0).try_acute().is_ok());
+		assert_eq!(Angle::Degrees(91.0).try_acute().unwrap_err(), AngleNotInRange::ObtuseAngle(91.0));
+	}
+
+	#[test]
+	fn try_obtuse_or_less_rejects_reflex_angles() {
+		assert!(Angle::Degrees(179.0).try_obtuse_or_less().is_ok());
+		assert_eq!(Angle::Degrees(181.0).try_obtuse_or_less().unwrap_err(), AngleNotInRange::ReflexAngle(181.0));
+	}
+
+	#[test]
+	fn try_within_turn_rejects_angles_over_a_full_turn() {
+		assert!(Angle::Degrees(360.0).try_within_turn().is_ok());
+		assert_eq!(Angle::Degrees(361.0).try_within_turn().unwrap_err(), AngleNotInRange::FullTurnExceeded(361.0));
+	}
+
+	#[test]
+	fn from_dms_checked_rejects_out_of_range_minutes_and_seconds() {
+		assert_eq!(Angle::<f64>::from_dms_checked((10, 60, 0)).unwrap_err(), AngleNotInRange::ArcMinutesOutOfRange(60));
+		assert_eq!(Angle::<f64>::from_dms_checked((10, 0, 60)).unwrap_err(), AngleNotInRange::ArcSecondsOutOfRange(60));
+		assert!(Angle::<f64>::from_dms_checked((10, 30, 15)).is_ok());
+	}
+}
@@ -36,6 +36,35 @@ fn get_phrase() -> String {
 	}
 }
 
+/// Convert `angle` into the unit named by `unit`, if recognized (case-insensitively matching
+/// `degrees`/`deg`, `radians`/`rad`, `gradians`/`grad`/`gon`, or `turns`/`turn`).
+///
+/// If `unit` is `None` or unrecognized, falls back to toggling between degrees and radians, which
+/// was the only conversion this option supported before gradians and turns existed.
+fn convert_angle(angle: angle::Angle, unit: Option<&str>) -> angle::Angle {
+	match unit.map(str::to_lowercase).as_deref() {
+		Some("degrees") | Some("deg") => angle.to_degrees(),
+		Some("radians") | Some("rad") => angle.to_radians(),
+		Some("gradians") | Some("grad") | Some("gon") => angle.to_gradians(),
+		Some("turns") | Some("turn") => angle.to_turns(),
+		Some(other) => {
+			eprintln!("Unrecognized unit \"{}\"; falling back to the degrees/radians default.", other);
+			toggle_degrees_and_radians(angle)
+		},
+		None => toggle_degrees_and_radians(angle),
+	}
+}
+
+/// The conversion this option performed before gradians and turns existed: degrees become radians
+/// and anything else becomes degrees.
+fn toggle_degrees_and_radians(angle: angle::Angle) -> angle::Angle {
+	if angle.is_degrees() {
+		angle.to_radians()
+	} else {
+		angle.to_degrees()
+	}
+}
+
 fn get_angle() -> angle::Angle {
 	loop {
 		match prompt("Now enter an angle. Use \u{00B0} to indicate degrees and \"rad.\" to indicate radians: ") {
@@ -82,6 +111,14 @@ fn main() {
 			HasArg::Maybe,
 			Occur::Optional
 		)
+		.opt(
+			"u",
+			"unit",
+			"the unit to convert -a's angle to: degrees, radians, gradians, or turns (defaults to toggling between degrees and radians)",
+			"UNIT",
+			HasArg::Maybe,
+			Occur::Optional
+		)
 		.optflag("d", "dragon", "draw a dragon")
 	;
 
@@ -126,11 +163,7 @@ fn main() {
 			}
 		};
 
-		println!("The angle you entered is {}.", if angle.is_degrees() {
-			angle.to_radians()
-		} else {
-			angle.to_degrees()
-		});
+		println!("The angle you entered is {}.", convert_angle(angle, matches.opt_str("u").as_deref()));
 	}
 
 	// try to draw a dragon